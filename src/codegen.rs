@@ -0,0 +1,255 @@
+//! Ahead-of-time transpilation from the optimized IR to standalone C or
+//! Rust source, so a hot Brainfuck program can be handed to a real
+//! compiler instead of run through `Interpreter`.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String};
+
+use crate::ir::Op;
+
+/// The language to emit source for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    C,
+    Rust,
+}
+
+/// Controls the shape of the tape in generated code.
+#[derive(Debug, Clone, Copy)]
+pub struct CodegenOptions {
+    /// Number of cells in the emitted tape array.
+    pub tape_size: usize,
+    /// Whether pointer movement wraps around the tape (`true`, the
+    /// classic Brainfuck convention) or is checked against the bounds
+    /// after every move, so walking off either end stops the program with
+    /// an out-of-bounds error (`false`).
+    pub wrapping: bool,
+}
+
+impl Default for CodegenOptions {
+    fn default() -> Self {
+        Self {
+            tape_size: 30_000,
+            wrapping: true,
+        }
+    }
+}
+
+/// Lowers optimized IR to source text for `target`.
+pub fn emit(ops: &[Op], target: Target, opts: CodegenOptions) -> String {
+    match target {
+        Target::C => emit_c(ops, opts),
+        Target::Rust => emit_rust(ops, opts),
+    }
+}
+
+fn emit_c(ops: &[Op], opts: CodegenOptions) -> String {
+    let mut out = String::new();
+    out.push_str("#include <stdio.h>\n#include <string.h>\n\n");
+    out.push_str(&format!("#define TAPE_SIZE {}\n\n", opts.tape_size));
+    out.push_str("int main(void) {\n");
+    out.push_str("    unsigned char tape[TAPE_SIZE];\n");
+    out.push_str("    memset(tape, 0, sizeof(tape));\n");
+    out.push_str("    size_t p = TAPE_SIZE / 2;\n\n");
+
+    let mut indent = 1usize;
+    for op in ops {
+        let pad = "    ".repeat(indent);
+        match *op {
+            Op::Add(n) => {
+                out.push_str(&format!("{pad}tape[p] = (unsigned char)(tape[p] + ({n}));\n"))
+            }
+            Op::Move(n) => emit_c_move(&mut out, &pad, n, opts.wrapping),
+            Op::Set(v) => out.push_str(&format!("{pad}tape[p] = {v};\n")),
+            Op::Print => out.push_str(&format!("{pad}putchar(tape[p]);\n")),
+            Op::Input => out.push_str(&format!(
+                "{pad}{{ int c = getchar(); tape[p] = (c == EOF) ? 0 : (unsigned char)c; }}\n"
+            )),
+            Op::LoopStart(_) => {
+                out.push_str(&format!("{pad}while (tape[p]) {{\n"));
+                indent += 1;
+            }
+            Op::LoopEnd(_) => {
+                indent -= 1;
+                let pad = "    ".repeat(indent);
+                out.push_str(&format!("{pad}}}\n"));
+            }
+            Op::MulAdd { offset, factor } => {
+                emit_c_muladd(&mut out, &pad, offset, factor, opts.wrapping)
+            }
+        }
+    }
+
+    out.push_str("    return 0;\n}\n");
+    out
+}
+
+fn emit_c_move(out: &mut String, pad: &str, n: isize, wrapping: bool) {
+    if wrapping {
+        out.push_str(&format!(
+            "{pad}p = (size_t)(((long)p + ({n})) % TAPE_SIZE + TAPE_SIZE) % TAPE_SIZE;\n"
+        ));
+    } else {
+        out.push_str(&format!("{pad}p += ({n});\n"));
+        out.push_str(&format!(
+            "{pad}if (p >= TAPE_SIZE) {{ fprintf(stderr, \"tape pointer out of bounds\\n\"); return 1; }}\n"
+        ));
+    }
+}
+
+/// A `MulAdd`'s target index is `p + offset`, wrapped the same way `Move`
+/// wraps the pointer itself in wrapping mode. In non-wrapping mode it gets
+/// the same out-of-bounds check `emit_c_move` gives `Move`, so a `MulAdd`
+/// that walks past either end of the tape fails loudly instead of
+/// indexing out of bounds.
+fn emit_c_muladd(out: &mut String, pad: &str, offset: isize, factor: i8, wrapping: bool) {
+    if wrapping {
+        let idx =
+            format!("((size_t)(((long)p + ({offset})) % TAPE_SIZE + TAPE_SIZE) % TAPE_SIZE)");
+        out.push_str(&format!(
+            "{pad}tape[{idx}] = (unsigned char)(tape[{idx}] + tape[p] * ({factor}));\n"
+        ));
+    } else {
+        out.push_str(&format!("{pad}{{\n"));
+        out.push_str(&format!("{pad}    long idx = (long)p + ({offset});\n"));
+        out.push_str(&format!(
+            "{pad}    if (idx < 0 || (size_t)idx >= TAPE_SIZE) {{ fprintf(stderr, \"tape pointer out of bounds\\n\"); return 1; }}\n"
+        ));
+        out.push_str(&format!(
+            "{pad}    tape[(size_t)idx] = (unsigned char)(tape[(size_t)idx] + tape[p] * ({factor}));\n"
+        ));
+        out.push_str(&format!("{pad}}}\n"));
+    }
+}
+
+fn emit_rust(ops: &[Op], opts: CodegenOptions) -> String {
+    let mut out = String::new();
+    out.push_str("#![allow(unused_mut, unused_variables, unused_imports)]\n");
+    out.push_str(&format!("const TAPE_SIZE: usize = {};\n\n", opts.tape_size));
+    out.push_str("fn main() {\n");
+    out.push_str("    use std::io::{Read, Write};\n");
+    out.push_str("    let mut tape = [0u8; TAPE_SIZE];\n");
+    out.push_str("    let mut p: usize = TAPE_SIZE / 2;\n");
+    out.push_str("    let stdin = std::io::stdin();\n");
+    out.push_str("    let mut stdin = stdin.lock();\n");
+    out.push_str("    let stdout = std::io::stdout();\n");
+    out.push_str("    let mut stdout = stdout.lock();\n\n");
+
+    let mut indent = 1usize;
+    for op in ops {
+        let pad = "    ".repeat(indent);
+        match *op {
+            Op::Add(n) => out.push_str(&format!(
+                "{pad}tape[p] = tape[p].wrapping_add({n}i8 as u8);\n"
+            )),
+            Op::Move(n) => emit_rust_move(&mut out, &pad, n, opts.wrapping),
+            Op::Set(v) => out.push_str(&format!("{pad}tape[p] = {v};\n")),
+            Op::Print => out.push_str(&format!("{pad}let _ = stdout.write_all(&[tape[p]]);\n")),
+            Op::Input => out.push_str(&format!(
+                "{pad}{{ let mut b = [0u8; 1]; tape[p] = if stdin.read_exact(&mut b).is_ok() {{ b[0] }} else {{ 0 }}; }}\n"
+            )),
+            Op::LoopStart(_) => {
+                out.push_str(&format!("{pad}while tape[p] != 0 {{\n"));
+                indent += 1;
+            }
+            Op::LoopEnd(_) => {
+                indent -= 1;
+                let pad = "    ".repeat(indent);
+                out.push_str(&format!("{pad}}}\n"));
+            }
+            Op::MulAdd { offset, factor } => {
+                emit_rust_muladd(&mut out, &pad, offset, factor, opts.wrapping)
+            }
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn emit_rust_move(out: &mut String, pad: &str, n: isize, wrapping: bool) {
+    if wrapping {
+        out.push_str(&format!(
+            "{pad}p = ((p as isize + ({n})).rem_euclid(TAPE_SIZE as isize)) as usize;\n"
+        ));
+    } else {
+        out.push_str(&format!("{pad}p = (p as isize + ({n})) as usize;\n"));
+        out.push_str(&format!(
+            "{pad}assert!(p < TAPE_SIZE, \"tape pointer out of bounds\");\n"
+        ));
+    }
+}
+
+/// A `MulAdd`'s target index is `p + offset`, wrapped the same way `Move`
+/// wraps the pointer itself in wrapping mode. In non-wrapping mode it gets
+/// the same out-of-bounds assertion `emit_rust_move` gives `Move`, so a
+/// `MulAdd` that walks past either end of the tape panics instead of
+/// indexing out of bounds.
+fn emit_rust_muladd(out: &mut String, pad: &str, offset: isize, factor: i8, wrapping: bool) {
+    if wrapping {
+        let idx = format!("(((p as isize + ({offset})).rem_euclid(TAPE_SIZE as isize)) as usize)");
+        out.push_str(&format!(
+            "{pad}tape[{idx}] = tape[{idx}].wrapping_add(tape[p].wrapping_mul({factor}i8 as u8));\n"
+        ));
+    } else {
+        out.push_str(&format!("{pad}{{\n"));
+        out.push_str(&format!("{pad}    let idx = p as isize + ({offset});\n"));
+        out.push_str(&format!(
+            "{pad}    assert!(idx >= 0 && (idx as usize) < TAPE_SIZE, \"tape pointer out of bounds\");\n"
+        ));
+        out.push_str(&format!("{pad}    let idx = idx as usize;\n"));
+        out.push_str(&format!(
+            "{pad}    tape[idx] = tape[idx].wrapping_add(tape[p].wrapping_mul({factor}i8 as u8));\n"
+        ));
+        out.push_str(&format!("{pad}}}\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::compile;
+    use crate::lexer::Lexer;
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    fn ops(source: &str) -> Vec<Op> {
+        compile(&Lexer::from(source).lex()).expect("balanced program should compile")
+    }
+
+    fn opts(wrapping: bool) -> CodegenOptions {
+        CodegenOptions { tape_size: 100, wrapping }
+    }
+
+    #[test]
+    fn wrapping_c_move_uses_modulo_instead_of_a_bounds_check() {
+        let src = emit_c(&ops(">"), opts(true));
+        assert!(src.contains("% TAPE_SIZE"));
+        assert!(!src.contains("out of bounds"));
+    }
+
+    #[test]
+    fn non_wrapping_c_move_emits_a_bounds_check() {
+        let src = emit_c(&ops(">"), opts(false));
+        assert!(src.contains("out of bounds"));
+    }
+
+    #[test]
+    fn non_wrapping_c_muladd_emits_a_bounds_check() {
+        let src = emit_c(&ops("[->>+<<]"), opts(false));
+        assert!(src.contains("idx < 0 || (size_t)idx >= TAPE_SIZE"));
+    }
+
+    #[test]
+    fn wrapping_rust_move_uses_rem_euclid_instead_of_an_assert() {
+        let src = emit_rust(&ops(">"), opts(true));
+        assert!(src.contains("rem_euclid"));
+        assert!(!src.contains("out of bounds"));
+    }
+
+    #[test]
+    fn non_wrapping_rust_muladd_emits_a_bounds_assert() {
+        let src = emit_rust(&ops("[->>+<<]"), opts(false));
+        assert!(src.contains("idx >= 0 && (idx as usize) < TAPE_SIZE"));
+    }
+}
@@ -0,0 +1,75 @@
+//! A human-readable disassembly of compiled IR, for diagnosing infinite
+//! loops and off-by-one tape errors the plain interpreter gives no way to
+//! observe. The interactive single-step mode built on top of this lives in
+//! the `bfi` binary, since it's a terminal feature rather than something a
+//! `no_std` embedder would need.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String};
+
+use crate::ir::Op;
+
+/// Renders one line per op: its index, a mnemonic with the fused count
+/// (`Add +5`, `Move -3`, `Set 0`, ...), and for loops the resolved jump
+/// target index.
+pub fn disassemble(ops: &[Op]) -> String {
+    let mut out = String::new();
+    for (i, op) in ops.iter().enumerate() {
+        out.push_str(&format!("{i:>6}: {}\n", describe(op)));
+    }
+    out
+}
+
+/// The mnemonic for a single op, without its index. Shared by `disassemble`
+/// and the interactive debugger's current-instruction line.
+pub fn describe(op: &Op) -> String {
+    match *op {
+        Op::Add(n) => format!("Add {n:+}"),
+        Op::Move(n) => format!("Move {n:+}"),
+        Op::Set(v) => format!("Set {v}"),
+        Op::Print => "Print".into(),
+        Op::Input => "Input".into(),
+        Op::LoopStart(end) => format!("LoopStart -> {end}"),
+        Op::LoopEnd(start) => format!("LoopEnd -> {start}"),
+        Op::MulAdd { offset, factor } => format!("MulAdd [{offset:+}] *= {factor:+}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    #[test]
+    fn describes_each_op_with_its_fused_count_or_jump_target() {
+        assert_eq!(describe(&Op::Add(5)), "Add +5");
+        assert_eq!(describe(&Op::Add(-3)), "Add -3");
+        assert_eq!(describe(&Op::Move(-3)), "Move -3");
+        assert_eq!(describe(&Op::Set(0)), "Set 0");
+        assert_eq!(describe(&Op::Print), "Print");
+        assert_eq!(describe(&Op::Input), "Input");
+        assert_eq!(describe(&Op::LoopStart(7)), "LoopStart -> 7");
+        assert_eq!(describe(&Op::LoopEnd(1)), "LoopEnd -> 1");
+        assert_eq!(
+            describe(&Op::MulAdd { offset: 2, factor: -1 }),
+            "MulAdd [+2] *= -1"
+        );
+    }
+
+    #[test]
+    fn disassembles_a_program_into_one_indexed_line_per_op() {
+        let ops = vec![
+            Op::Add(1),
+            Op::LoopStart(3),
+            Op::MulAdd { offset: 1, factor: 2 },
+            Op::LoopEnd(1),
+        ];
+        let mut expected = String::new();
+        expected.push_str("     0: Add +1\n");
+        expected.push_str("     1: LoopStart -> 3\n");
+        expected.push_str("     2: MulAdd [+1] *= +2\n");
+        expected.push_str("     3: LoopEnd -> 1\n");
+        assert_eq!(disassemble(&ops), expected);
+    }
+}
@@ -0,0 +1,43 @@
+//! Structured error type for compilation failures, so malformed programs
+//! are reported with an exact source position instead of panicking partway
+//! through a run.
+
+#[cfg(not(feature = "std"))]
+use core::fmt;
+#[cfg(feature = "std")]
+use std::fmt;
+
+/// Something that went wrong compiling or running a Brainfuck program.
+#[derive(Debug)]
+pub enum Error {
+    /// A `[` with no matching `]`, at the 1-based source position of the `[`.
+    UnmatchedOpen { pos: usize },
+    /// A `]` with no matching `[`, at the 1-based source position of the `]`.
+    UnmatchedClose { pos: usize },
+    /// An I/O error, converted from `std::io::Error` via `From`. Nothing in
+    /// this crate produces it: execution does not return errors, so `,`
+    /// treats a read error like end of input and `.` discards write errors.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnmatchedOpen { pos } => write!(f, "unmatched '[' at position {pos}"),
+            Error::UnmatchedClose { pos } => write!(f, "unmatched ']' at position {pos}"),
+            #[cfg(feature = "std")]
+            Error::Io(err) => write!(f, "I/O error: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
@@ -0,0 +1,55 @@
+use std::borrow::Cow::{self, Owned};
+
+use rustyline::highlight::Highlighter;
+use rustyline::validate::{ValidationContext, ValidationResult, Validator};
+use rustyline::{Completer, Helper, Hinter};
+
+/// Helper wired into the REPL's `Editor` to provide multi-line bracket
+/// matching and BF-aware syntax highlighting.
+///
+/// Completion and hinting are left at their no-op defaults; only input
+/// validation and highlighting are meaningful for Brainfuck source.
+#[derive(Completer, Helper, Hinter, Default)]
+pub struct ReplHelper;
+
+impl Validator for ReplHelper {
+    fn validate(&self, ctx: &mut ValidationContext) -> rustyline::Result<ValidationResult> {
+        let mut depth = 0i64;
+        for c in ctx.input().chars() {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                _ => (),
+            }
+        }
+        if depth > 0 {
+            Ok(ValidationResult::Incomplete)
+        } else {
+            Ok(ValidationResult::Valid(None))
+        }
+    }
+}
+
+impl Highlighter for ReplHelper {
+    fn highlight<'l>(&self, line: &'l str, _pos: usize) -> Cow<'l, str> {
+        let mut out = String::with_capacity(line.len());
+        for c in line.chars() {
+            match c {
+                '<' => out.push_str(&format!("\x1b[33m{c}\x1b[0m")), // yellow: move left
+                '>' => out.push_str(&format!("\x1b[93m{c}\x1b[0m")), // bright yellow: move right
+                '+' => out.push_str(&format!("\x1b[32m{c}\x1b[0m")), // green: increment
+                '-' => out.push_str(&format!("\x1b[92m{c}\x1b[0m")), // bright green: decrement
+                '.' => out.push_str(&format!("\x1b[36m{c}\x1b[0m")), // cyan: print
+                ',' => out.push_str(&format!("\x1b[96m{c}\x1b[0m")), // bright cyan: input
+                '[' => out.push_str(&format!("\x1b[35m{c}\x1b[0m")), // magenta: loop start
+                ']' => out.push_str(&format!("\x1b[95m{c}\x1b[0m")), // bright magenta: loop end
+                _ => out.push_str(&format!("\x1b[2m{c}\x1b[0m")),    // dim: commentary
+            }
+        }
+        Owned(out)
+    }
+
+    fn highlight_char(&self, _line: &str, _pos: usize, _forced: bool) -> bool {
+        true
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::error::Error;
+use crate::io::{ByteInput, ByteOutput};
+use crate::ir::{self, Op};
+use crate::lexer::{Lexer, Token};
+
+/// Executes compiled Brainfuck IR against a growable tape, reading `,` input
+/// from `R` and writing `.` output to `W`. Both are generic over the minimal
+/// `ByteInput`/`ByteOutput` traits so the interpreter core has no hard
+/// dependency on `std::io` (see `crate::io`).
+pub struct Interpreter<R, W> {
+    ops: Vec<Op>,
+    program_cursor: usize,
+    mem_cursor: usize,
+    mem: Vec<u8>,
+    input: R,
+    output: W,
+}
+
+impl<R: ByteInput, W: ByteOutput> Interpreter<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        Self {
+            ops: Vec::new(),
+            program_cursor: 0,
+            mem_cursor: 0,
+            mem: vec![0],
+            input,
+            output,
+        }
+    }
+
+    pub fn from_source(source: &str, input: R, output: W) -> Result<Self, Error> {
+        let mut interpreter = Self::new(input, output);
+        interpreter.load(Lexer::from(source).lex())?;
+        Ok(interpreter)
+    }
+
+    /// Compiles freshly lexed tokens to IR and appends it to the program.
+    /// Used by the REPL to keep feeding the same interpreter new input
+    /// without losing tape state; loop targets in the new chunk are shifted
+    /// past whatever ops already ran.
+    pub fn load(&mut self, tokens: Vec<Token>) -> Result<(), Error> {
+        let base = self.ops.len();
+        let chunk = ir::compile(&tokens)?.into_iter().map(|op| match op {
+            Op::LoopStart(end) => Op::LoopStart(end + base),
+            Op::LoopEnd(start) => Op::LoopEnd(start + base),
+            other => other,
+        });
+        self.ops.extend(chunk);
+        Ok(())
+    }
+
+    pub fn interpret(&mut self) {
+        while self.step() {}
+    }
+
+    /// Executes the op at `program_cursor`, if any, and advances past it.
+    /// Returns `false` once the program has run off the end of `ops`, which
+    /// the debugger uses to single-step instead of running to completion.
+    pub fn step(&mut self) -> bool {
+        let Some(op) = self.ops.get(self.program_cursor).copied() else {
+            return false;
+        };
+        match op {
+            Op::Add(n) => {
+                self.mem[self.mem_cursor] = self.mem[self.mem_cursor].wrapping_add(n as u8)
+            }
+            Op::Move(n) => self.apply_move(n),
+            Op::Set(v) => self.mem[self.mem_cursor] = v,
+            Op::Print => self.output.write_byte(self.mem[self.mem_cursor]),
+            Op::Input => {
+                // On EOF, set the cell to 0 (the common convention) rather than
+                // leaving it unchanged, so idioms like the cat loop `,[.,]`
+                // terminate instead of spinning on a stale nonzero counter.
+                self.mem[self.mem_cursor] = self.input.read_byte().unwrap_or(0);
+            }
+            Op::LoopStart(end) => {
+                if self.mem[self.mem_cursor] == 0 {
+                    self.program_cursor = end;
+                }
+            }
+            Op::LoopEnd(start) => {
+                if self.mem[self.mem_cursor] != 0 {
+                    self.program_cursor = start;
+                }
+            }
+            Op::MulAdd { offset, factor } => {
+                let current = self.mem[self.mem_cursor];
+                if current != 0 {
+                    let idx = self.cell_at(offset);
+                    let add = ((current as i32) * (factor as i32)) as u8;
+                    self.mem[idx] = self.mem[idx].wrapping_add(add);
+                }
+            }
+        }
+        self.program_cursor += 1;
+        true
+    }
+
+    pub fn ops(&self) -> &[Op] {
+        &self.ops
+    }
+
+    pub fn program_cursor(&self) -> usize {
+        self.program_cursor
+    }
+
+    pub fn mem_cursor(&self) -> usize {
+        self.mem_cursor
+    }
+
+    pub fn tape(&self) -> &[u8] {
+        &self.mem
+    }
+
+    pub fn output(&self) -> &W {
+        &self.output
+    }
+
+    /// Moves the cursor by `n` cells, growing the tape on whichever side is
+    /// walked off the end of, exactly as repeated single-cell moves would.
+    fn apply_move(&mut self, mut n: isize) {
+        while n > 0 {
+            self.mem_cursor += 1;
+            if self.mem_cursor >= self.mem.len() {
+                self.mem.push(0);
+            }
+            n -= 1;
+        }
+        while n < 0 {
+            if self.mem_cursor == 0 {
+                self.mem.insert(0, 0);
+            } else {
+                self.mem_cursor -= 1;
+            }
+            n += 1;
+        }
+    }
+
+    /// Resolves the tape index for `offset` cells from the cursor, growing
+    /// the tape (and shifting the cursor to match) if `offset` reaches past
+    /// either end.
+    fn cell_at(&mut self, offset: isize) -> usize {
+        let mut target = self.mem_cursor as isize + offset;
+        while target < 0 {
+            self.mem.insert(0, 0);
+            self.mem_cursor += 1;
+            target += 1;
+        }
+        while target as usize >= self.mem.len() {
+            self.mem.push(0);
+        }
+        target as usize
+    }
+}
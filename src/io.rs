@@ -0,0 +1,51 @@
+//! Minimal byte I/O abstraction so the interpreter core has no hard
+//! dependency on `std`. Under the `std` feature (the default), any
+//! `std::io::Read`/`Write` implementer gets these for free; without it,
+//! callers (e.g. firmware, WASM) implement `ByteInput`/`ByteOutput` directly
+//! against whatever byte source/sink they have.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// A source of input bytes for the `,` command.
+pub trait ByteInput {
+    /// Returns the next input byte, or `None` once the source is exhausted.
+    fn read_byte(&mut self) -> Option<u8>;
+}
+
+/// A sink for output bytes produced by the `.` command.
+pub trait ByteOutput {
+    fn write_byte(&mut self, byte: u8);
+}
+
+#[cfg(feature = "std")]
+impl<T: std::io::Read> ByteInput for T {
+    fn read_byte(&mut self) -> Option<u8> {
+        let mut buf = [0u8; 1];
+        self.read_exact(&mut buf).ok()?;
+        Some(buf[0])
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: std::io::Write> ByteOutput for T {
+    fn write_byte(&mut self, byte: u8) {
+        let _ = self.write_all(&[byte]);
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl ByteInput for &[u8] {
+    fn read_byte(&mut self) -> Option<u8> {
+        let (&first, rest) = self.split_first()?;
+        *self = rest;
+        Some(first)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl ByteOutput for Vec<u8> {
+    fn write_byte(&mut self, byte: u8) {
+        self.push(byte);
+    }
+}
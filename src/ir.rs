@@ -0,0 +1,263 @@
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+use crate::error::Error;
+use crate::lexer::{Token, TokenKind};
+
+/// A single optimized operation. `interpret` runs a flat `Vec<Op>` instead of
+/// walking raw tokens one command at a time, so runs of `+`/`-`/`<`/`>` and
+/// a handful of common loop idioms are folded away during `compile`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    /// Add a wrapping `i8` delta to the current cell.
+    Add(i8),
+    /// Move the cursor by a signed number of cells.
+    Move(isize),
+    /// Set the current cell to an exact value (from a recognized `[-]`/`[+]`).
+    Set(u8),
+    Print,
+    Input,
+    /// Jump to the matching `LoopEnd` index if the current cell is zero.
+    LoopStart(usize),
+    /// Jump to the matching `LoopStart` index if the current cell is nonzero.
+    LoopEnd(usize),
+    /// Recognized multiply/copy-loop step: add `factor * current_cell` to the
+    /// cell at `offset` from the loop's start cell.
+    MulAdd { offset: isize, factor: i8 },
+}
+
+/// Lowers a token stream into the optimized IR described above. Fails if the
+/// brackets aren't balanced, reporting the exact source position of the
+/// offending `[` or `]`.
+pub fn compile(tokens: &[Token]) -> Result<Vec<Op>, Error> {
+    let matches = match_brackets(tokens)?;
+    let mut ops = Vec::new();
+    lower_range(tokens, &matches, 0, tokens.len(), &mut ops);
+    Ok(ops)
+}
+
+fn match_brackets(tokens: &[Token]) -> Result<BTreeMap<usize, usize>, Error> {
+    let mut table = BTreeMap::new();
+    let mut stack = Vec::new();
+    for (i, tok) in tokens.iter().enumerate() {
+        match tok.kind {
+            TokenKind::Jmp => stack.push(i),
+            TokenKind::Pmj => {
+                let open = stack
+                    .pop()
+                    .ok_or(Error::UnmatchedClose { pos: tok.pos })?;
+                table.insert(open, i);
+                table.insert(i, open);
+            }
+            _ => (),
+        }
+    }
+    if let Some(&open) = stack.first() {
+        return Err(Error::UnmatchedOpen {
+            pos: tokens[open].pos,
+        });
+    }
+    Ok(table)
+}
+
+fn lower_range(
+    tokens: &[Token],
+    matches: &BTreeMap<usize, usize>,
+    start: usize,
+    end: usize,
+    ops: &mut Vec<Op>,
+) {
+    let mut i = start;
+    while i < end {
+        match tokens[i].kind {
+            TokenKind::Increment | TokenKind::Decrement => {
+                let mut delta: i32 = 0;
+                while i < end
+                    && matches!(tokens[i].kind, TokenKind::Increment | TokenKind::Decrement)
+                {
+                    delta += if tokens[i].kind == TokenKind::Increment {
+                        1
+                    } else {
+                        -1
+                    };
+                    i += 1;
+                }
+                ops.push(Op::Add(wrap_delta(delta)));
+            }
+            TokenKind::MoveLeft | TokenKind::MoveRight => {
+                let mut delta: isize = 0;
+                while i < end
+                    && matches!(tokens[i].kind, TokenKind::MoveLeft | TokenKind::MoveRight)
+                {
+                    delta += if tokens[i].kind == TokenKind::MoveRight {
+                        1
+                    } else {
+                        -1
+                    };
+                    i += 1;
+                }
+                ops.push(Op::Move(delta));
+            }
+            TokenKind::Print => {
+                ops.push(Op::Print);
+                i += 1;
+            }
+            TokenKind::Input => {
+                ops.push(Op::Input);
+                i += 1;
+            }
+            TokenKind::Jmp => {
+                let close = matches[&i];
+                if let Some(idiom) = recognize_loop_idiom(tokens, i + 1, close) {
+                    ops.extend(idiom);
+                } else {
+                    let start_idx = ops.len();
+                    ops.push(Op::LoopStart(0)); // patched once the body is lowered
+                    lower_range(tokens, matches, i + 1, close, ops);
+                    let end_idx = ops.len();
+                    ops.push(Op::LoopEnd(start_idx));
+                    ops[start_idx] = Op::LoopStart(end_idx);
+                }
+                i = close + 1;
+            }
+            TokenKind::Pmj => unreachable!("']' is consumed by its matching '['"),
+        }
+    }
+}
+
+/// Tries to recognize `tokens[body_start..body_end]` (the contents of a
+/// loop, excluding the brackets themselves) as a known idiom. Returns the
+/// replacement ops on success, or `None` to fall back to a plain loop.
+fn recognize_loop_idiom(tokens: &[Token], body_start: usize, body_end: usize) -> Option<Vec<Op>> {
+    let body = &tokens[body_start..body_end];
+
+    // `[-]` / `[+]`: clear the current cell.
+    if body.len() == 1 && matches!(body[0].kind, TokenKind::Increment | TokenKind::Decrement) {
+        return Some(vec![Op::Set(0)]);
+    }
+
+    // Multiply/copy loop: a balanced (net-zero movement) body made only of
+    // `+`/`-`/`<`/`>` that decrements the start cell by exactly one per pass.
+    if body
+        .iter()
+        .any(|t| matches!(t.kind, TokenKind::Jmp | TokenKind::Pmj | TokenKind::Print | TokenKind::Input))
+    {
+        return None;
+    }
+
+    let mut offset: isize = 0;
+    let mut deltas: BTreeMap<isize, i32> = BTreeMap::new();
+    for tok in body {
+        match tok.kind {
+            TokenKind::Increment => *deltas.entry(offset).or_insert(0) += 1,
+            TokenKind::Decrement => *deltas.entry(offset).or_insert(0) -= 1,
+            TokenKind::MoveRight => offset += 1,
+            TokenKind::MoveLeft => offset -= 1,
+            _ => unreachable!("filtered above"),
+        }
+    }
+
+    if offset != 0 || deltas.get(&0) != Some(&-1) {
+        return None;
+    }
+
+    let mut touched: Vec<isize> = deltas
+        .iter()
+        .filter(|(&off, &delta)| off != 0 && delta != 0)
+        .map(|(&off, _)| off)
+        .collect();
+    touched.sort_unstable();
+
+    let mut idiom = Vec::with_capacity(touched.len() + 1);
+    for off in touched {
+        idiom.push(Op::MulAdd {
+            offset: off,
+            factor: wrap_delta(deltas[&off]),
+        });
+    }
+    idiom.push(Op::Set(0));
+    Some(idiom)
+}
+
+fn wrap_delta(delta: i32) -> i8 {
+    (delta.rem_euclid(256)) as u8 as i8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+
+    fn compile_source(source: &str) -> Vec<Op> {
+        compile(&Lexer::from(source).lex()).expect("balanced program should compile")
+    }
+
+    fn run(source: &str) -> Vec<u8> {
+        let mut interpreter =
+            crate::Interpreter::from_source(source, &b""[..], Vec::new()).expect("valid program");
+        interpreter.interpret();
+        interpreter.output().clone()
+    }
+
+    #[test]
+    fn coalesces_runs_of_increments_and_moves() {
+        assert_eq!(compile_source("+++>>"), vec![Op::Add(3), Op::Move(2)]);
+        assert_eq!(compile_source("--<"), vec![Op::Add(-2), Op::Move(-1)]);
+    }
+
+    #[test]
+    fn recognizes_clear_loop_idiom() {
+        assert_eq!(compile_source("[-]"), vec![Op::Set(0)]);
+        assert_eq!(compile_source("[+]"), vec![Op::Set(0)]);
+    }
+
+    #[test]
+    fn recognizes_multiply_loop_idiom() {
+        assert_eq!(
+            compile_source("[->++<]"),
+            vec![Op::MulAdd { offset: 1, factor: 2 }, Op::Set(0)]
+        );
+    }
+
+    #[test]
+    fn falls_back_to_plain_loop_when_body_has_io() {
+        assert_eq!(
+            compile_source("[-.]"),
+            vec![Op::LoopStart(3), Op::Add(-1), Op::Print, Op::LoopEnd(0)]
+        );
+    }
+
+    #[test]
+    fn hello_world_runs_to_expected_output_through_coalesced_and_idiom_ops() {
+        let program = "++++++++[>++++[>++>+++>+++>+<<<<-]>+>+>->>+[<]<-]>>.>---.+++++++..+++.>>.<-.<.+++.------.--------.>>+.>++.";
+        assert_eq!(run(program), b"Hello World!\n");
+    }
+
+    #[test]
+    fn negative_offsets_grow_the_tape_left_and_still_resolve_muladd_targets() {
+        // Moves left of the starting cell before running a multiply loop,
+        // exercising the left-growing tape path in `Interpreter::apply_move`
+        // and checking the `MulAdd` target still resolves on the grown tape.
+        assert_eq!(run("<<+++[->>+<<]>>."), run("+++[->+<]>."));
+    }
+
+    #[test]
+    fn reports_the_position_of_a_stray_leading_close_bracket() {
+        let tokens = Lexer::from("]++").lex();
+        match compile(&tokens) {
+            Err(Error::UnmatchedClose { pos }) => assert_eq!(pos, 1),
+            other => panic!("expected UnmatchedClose at pos 1, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reports_the_position_of_an_unclosed_trailing_open_bracket() {
+        let tokens = Lexer::from("++[--").lex();
+        match compile(&tokens) {
+            Err(Error::UnmatchedOpen { pos }) => assert_eq!(pos, 3),
+            other => panic!("expected UnmatchedOpen at pos 3, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::str::Chars;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TokenKind {
+    MoveLeft,  // <
+    MoveRight, // >
+    Increment, // +
+    Decrement, // -
+    Print,     // .
+    Input,     // ,
+    Jmp,       // [
+    Pmj,       // ]
+}
+
+#[derive(Debug)]
+pub struct Token {
+    pub kind: TokenKind,
+    /// 1-based source position, used to report exact bracket-mismatch errors.
+    pub pos: usize,
+}
+
+impl Token {
+    pub fn from(kind: TokenKind, pos: usize) -> Self {
+        Self { kind, pos }
+    }
+}
+
+pub struct Lexer<'a> {
+    buffer: Chars<'a>,
+}
+
+impl Lexer<'_> {
+    pub fn lex(&mut self) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        let mut pos: usize = 1;
+        loop {
+            match self.buffer.next() {
+                None => break,
+                Some(c) => {
+                    if let Some(token) = Self::identify(c) {
+                        tokens.push(Token::from(token, pos));
+                    }
+                }
+            }
+            pos += 1;
+        }
+        tokens
+    }
+
+    fn identify(c: char) -> Option<TokenKind> {
+        use TokenKind::*;
+        match c {
+            '<' => Some(MoveLeft),
+            '>' => Some(MoveRight),
+            '+' => Some(Increment),
+            '-' => Some(Decrement),
+            '.' => Some(Print),
+            ',' => Some(Input),
+            '[' => Some(Jmp),
+            ']' => Some(Pmj),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Lexer<'a> {
+    fn from(buffer: &'a str) -> Self {
+        let buffer = buffer.chars();
+        Self { buffer }
+    }
+}
@@ -0,0 +1,33 @@
+//! Core Brainfuck lexer, optimizing IR compiler, and interpreter, usable as
+//! a library independent of the `bfi` binary. Compiles without `std` when
+//! built with `--no-default-features` for embedding in firmware or WASM;
+//! callers then supply their own `ByteInput`/`ByteOutput` (see `io`).
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+pub mod codegen;
+pub mod debug;
+pub mod error;
+pub mod interpreter;
+pub mod io;
+pub mod ir;
+pub mod lexer;
+
+pub use error::Error;
+pub use interpreter::Interpreter;
+pub use lexer::{Lexer, Token, TokenKind};
+
+/// Runs a Brainfuck program to completion, reading `,` input from `input`
+/// and writing `.` output to `output`. Fails if the program's brackets
+/// aren't balanced.
+#[cfg(feature = "std")]
+pub fn run(
+    program: &str,
+    input: impl std::io::Read,
+    output: impl std::io::Write,
+) -> Result<(), Error> {
+    Interpreter::from_source(program, input, output)?.interpret();
+    Ok(())
+}
@@ -0,0 +1,203 @@
+use std::io::{BufRead, Write};
+use std::{env, fs, io, process};
+
+use bfi::{Interpreter, Lexer};
+use rustyline::error::ReadlineError;
+use rustyline::Editor;
+
+mod helper;
+use helper::ReplHelper;
+
+/// Prints a usage error to stderr and exits with status 1, used for
+/// malformed CLI input (a missing flag value, an unknown flag, a file that
+/// can't be read) rather than panicking the process.
+fn usage_error(msg: &str) -> ! {
+    eprintln!("error: {msg}");
+    process::exit(1);
+}
+
+fn launch_repl() {
+    let mut rl: Editor<ReplHelper, rustyline::history::DefaultHistory> =
+        Editor::new().expect("Failed to start line editor");
+    rl.set_helper(Some(ReplHelper));
+
+    let mut interpreter = Interpreter::new(io::stdin(), io::stdout());
+    loop {
+        match rl.readline(">> ") {
+            Ok(line) => {
+                let _ = rl.add_history_entry(line.as_str());
+                let tokens = Lexer::from(line.as_str()).lex();
+                match interpreter.load(tokens) {
+                    Ok(()) => interpreter.interpret(),
+                    Err(err) => eprintln!("error: {err}"),
+                }
+                println!();
+            }
+            Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => break,
+            Err(err) => {
+                eprintln!("Readline error: {err}");
+                break;
+            }
+        }
+    }
+}
+
+/// Handles `bfi --emit <c|rust> <file> [--tape-size N] [--no-wrap] [-o out]`.
+fn emit_command(args: &[String]) {
+    let mut target = None;
+    let mut path = None;
+    let mut output_path = None;
+    let mut opts = bfi::codegen::CodegenOptions::default();
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--tape-size" => {
+                i += 1;
+                let value = args
+                    .get(i)
+                    .unwrap_or_else(|| usage_error("--tape-size expects a value"));
+                opts.tape_size = value
+                    .parse()
+                    .unwrap_or_else(|_| usage_error("--tape-size expects a number"));
+            }
+            "--no-wrap" => opts.wrapping = false,
+            "-o" => {
+                i += 1;
+                let value = args
+                    .get(i)
+                    .unwrap_or_else(|| usage_error("-o expects a value"));
+                output_path = Some(value.clone());
+            }
+            other if target.is_none() => target = Some(other.to_string()),
+            other if path.is_none() => path = Some(other.to_string()),
+            other => usage_error(&format!("unexpected argument: {other}")),
+        }
+        i += 1;
+    }
+
+    let target = match target.as_deref() {
+        Some("c") => bfi::codegen::Target::C,
+        Some("rust") => bfi::codegen::Target::Rust,
+        Some(other) => usage_error(&format!("unknown --emit target: {other} (expected c or rust)")),
+        None => usage_error("--emit requires a target (c or rust)"),
+    };
+    let path = path.unwrap_or_else(|| usage_error("--emit requires a source file path"));
+    let source =
+        fs::read_to_string(&path).unwrap_or_else(|err| usage_error(&format!("failed to read {path}: {err}")));
+    let tokens = Lexer::from(source.as_str()).lex();
+    let ops = bfi::ir::compile(&tokens).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        process::exit(1);
+    });
+    let code = bfi::codegen::emit(&ops, target, opts);
+
+    match output_path {
+        Some(out) => fs::write(&out, code)
+            .unwrap_or_else(|err| usage_error(&format!("failed to write {out}: {err}"))),
+        None => print!("{code}"),
+    }
+}
+
+/// Handles `bfi --debug <file>`: prints the compiled IR's disassembly, then
+/// lets the user step through execution one op at a time, watching the
+/// tape around the cursor as it changes.
+fn debug_command(path: &str) {
+    let source = fs::read_to_string(path)
+        .unwrap_or_else(|err| usage_error(&format!("failed to read {path}: {err}")));
+    let tokens = Lexer::from(source.as_str()).lex();
+    let ops = bfi::ir::compile(&tokens).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        process::exit(1);
+    });
+    print!("{}", bfi::debug::disassemble(&ops));
+
+    let mut interpreter =
+        Interpreter::from_source(&source, io::stdin(), io::stdout()).unwrap_or_else(|err| {
+            eprintln!("error: {err}");
+            process::exit(1);
+        });
+    println!("\nInteractive debugger: [s]tep (default), [c]ontinue, [i]nspect <cell>, [q]uit");
+
+    let stdin = io::stdin();
+    loop {
+        if interpreter.program_cursor() >= interpreter.ops().len() {
+            println!("Program finished.");
+            break;
+        }
+        let next = interpreter.ops()[interpreter.program_cursor()];
+        println!(
+            "pc={} mem_cursor={} next: {}",
+            interpreter.program_cursor(),
+            interpreter.mem_cursor(),
+            bfi::debug::describe(&next)
+        );
+        print_tape_window(&interpreter);
+
+        print!("(debug) ");
+        let _ = io::stdout().flush();
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 {
+            break; // EOF
+        }
+        let line = line.trim();
+        let mut words = line.split_whitespace();
+        match words.next().unwrap_or("s") {
+            "s" => {
+                if !interpreter.step() {
+                    println!("Program finished.");
+                    break;
+                }
+            }
+            "c" => {
+                interpreter.interpret();
+                println!("Program finished.");
+                break;
+            }
+            "i" => match words.next().and_then(|n| n.parse::<usize>().ok()) {
+                Some(idx) => match interpreter.tape().get(idx) {
+                    Some(v) => println!("cell[{idx}] = {v}"),
+                    None => println!("cell[{idx}] is outside the current tape"),
+                },
+                None => println!("usage: i <cell index>"),
+            },
+            "q" => break,
+            other => println!("unknown command: {other}"),
+        }
+    }
+}
+
+fn print_tape_window(interpreter: &Interpreter<io::Stdin, io::Stdout>) {
+    let tape = interpreter.tape();
+    let cursor = interpreter.mem_cursor();
+    const RADIUS: usize = 4;
+    let start = cursor.saturating_sub(RADIUS);
+    let end = (cursor + RADIUS + 1).min(tape.len());
+    let mut line = String::new();
+    for (i, cell) in tape.iter().enumerate().take(end).skip(start) {
+        if i == cursor {
+            line.push_str(&format!("[{cell}] "));
+        } else {
+            line.push_str(&format!("{cell} "));
+        }
+    }
+    println!("tape: {line}");
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.as_slice() {
+        [] => launch_repl(),
+        [first, rest @ ..] if first == "--emit" => emit_command(rest),
+        [first, path] if first == "--debug" => debug_command(path),
+        [path] => {
+            let source = fs::read_to_string(path)
+                .unwrap_or_else(|err| usage_error(&format!("failed to read {path}: {err}")));
+            if let Err(err) = bfi::run(&source, io::stdin(), io::stdout()) {
+                eprintln!("error: {err}");
+                process::exit(1);
+            }
+        }
+        _ => eprintln!("Too many arguments"),
+    };
+}